@@ -1,6 +1,45 @@
-use std::{env, fmt::Display, ops::{Sub, Div}};
+use std::{fmt, fmt::Display, ops::{Add, Sub, Mul, Div}};
 // use std::{sync::mpsc::{Sender, Receiver, channel}, thread::{Thread,spawn, JoinHandle}};
-use num_dual::{DualNumFloat,Dual32};
+use num_dual::{DualNumFloat,Dual32,Dual2_32};
+use num_complex::Complex;
+
+/// The ways in which a root-finding routine can fail to produce a usable answer.
+///
+/// This mirrors the error model used by other numerical crates (e.g. Peroxide's
+/// root finder): failures are reported as values rather than as panics, so callers
+/// can distinguish "no root in this interval" from "the iteration blew up".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RootError<T> where T: DualNumFloat {
+    /// `patience` iterations were exhausted without converging.
+    TimesUp { last: T, iterations: i32 },
+    /// The iterate or its derivative became `NaN`/`Inf` during the search.
+    NaNEncountered,
+    /// The derivative evaluated to (near) zero, so the Newton step would divide by zero.
+    ZeroDerivative,
+    /// The chosen [`RootMethod`]/[`IterationOrder`] needs a derivative (`f'` or `f''`)
+    /// that the caller didn't supply, e.g. calling [`Halley`]/[`Schroder`] without `f''`.
+    MissingDerivative,
+    /// The bounds passed to `root_search` do not describe a valid interval.
+    InvalidBounds,
+}
+
+impl<T: DualNumFloat> Display for RootError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RootError::TimesUp { last, iterations } => write!(
+                f,
+                "failed to converge after {} iterations, last iterate was {}",
+                iterations, last
+            ),
+            RootError::NaNEncountered => write!(f, "iterate or derivative became NaN/Inf"),
+            RootError::ZeroDerivative => write!(f, "derivative is zero, cannot take a Newton step"),
+            RootError::MissingDerivative => write!(f, "chosen method requires a derivative that was not supplied"),
+            RootError::InvalidBounds => write!(f, "lower bound must be strictly less than upper bound"),
+        }
+    }
+}
+
+impl<T: DualNumFloat + fmt::Debug> std::error::Error for RootError<T> {}
 
 pub trait Derivable<T> where T: DualNumFloat {
     fn execute_derivative(&self) -> Self;
@@ -15,29 +54,88 @@ pub trait Coerceable<T> where T: DualNumFloat{
 
 impl Derivable<f32> for Dual32 {
     fn execute_derivative(&self) -> Self {
-        return self.derivative()
+        self.derivative()
     }
     fn zeroth_derivative(&self) -> f32 {
-        return self.re
+        self.re
     }
     fn first_derivative(&self) -> f32 {
-        return self.eps
+        self.eps
     }
 }
 
 impl <T: DualNumFloat> Coerceable<T> for Dual32 {
     fn coerce_to(&self) -> T {
-        return T::from(self.re).unwrap()
+        T::from(self.re).unwrap()
     }
     fn coerce_from(value: T) -> Self {
-        return Dual32::from_re(value.to_f32().unwrap())
+        Dual32::from_re(value.to_f32().unwrap())
+    }
+}
+
+/// Extends [`Derivable`] with a second derivative, backed by `num_dual`'s
+/// `Dual2` family. Implementors let [`root_search`] callers use Halley
+/// iteration, which converges cubically near simple roots, or Schröder
+/// iteration, which stays quadratically convergent near multiple roots.
+pub trait Derivable2<T>: Derivable<T> where T: DualNumFloat {
+    fn execute_second_derivative(&self) -> Self;
+    fn second_derivative(&self) -> T;
+}
+
+impl Derivable<f32> for Dual2_32 {
+    fn execute_derivative(&self) -> Self {
+        self.derivative()
+    }
+    fn zeroth_derivative(&self) -> f32 {
+        self.re
+    }
+    fn first_derivative(&self) -> f32 {
+        self.v1
+    }
+}
+
+impl Derivable2<f32> for Dual2_32 {
+    fn execute_second_derivative(&self) -> Self {
+        self.derivative()
+    }
+    fn second_derivative(&self) -> f32 {
+        self.v2
     }
 }
 
+impl <T: DualNumFloat> Coerceable<T> for Dual2_32 {
+    fn coerce_to(&self) -> T {
+        T::from(self.re).unwrap()
+    }
+    fn coerce_from(value: T) -> Self {
+        Dual2_32::from_re(value.to_f32().unwrap())
+    }
+}
+
+/// Selects which iteration formula `bracketed_newton` applies at each step.
+///
+/// `Newton` falls back to the plain quadratic update; `Halley` and `Schroder`
+/// require a usable second derivative. `Halley` converges cubically near
+/// simple roots; `Schroder` (Newton applied to `f/f'`) converges
+/// quadratically, including near multiple roots where `Halley` (and plain
+/// Newton) slow back down to linear convergence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IterationOrder {
+    Newton,
+    Halley,
+    Schroder
+}
+
 pub struct NewtonOptions<T> where T: DualNumFloat {
     pub guess: T,
     pub patience: i32,
-    pub tolerance: T
+    pub tolerance: T,
+    pub debug: bool,
+    pub order: IterationOrder,
+    /// When set, `NewtonResult::history` is populated with every `(x_n, f(x_n))`
+    /// pair visited, so slow or stalling convergence can be diagnosed without
+    /// re-instrumenting the solver.
+    pub trace: bool
 }
 
 pub struct BisectionOptions<T> where T: DualNumFloat {
@@ -51,14 +149,25 @@ pub struct RootSearchOptions<T> where T: DualNumFloat {
     pub tolerance: T,
     pub lower: T,
     pub upper: T,
-    pub resolution: i32
+    pub resolution: i32,
+    pub debug: bool,
+    pub method: Box<dyn RootMethod<T>>,
+    pub trace: bool
 }
 
 pub struct NewtonResult<T> where T: DualNumFloat {
-    pub root: Option<T>,
-    pub iterations: i32
+    pub root: T,
+    pub iterations: i32,
+    /// An estimate of how ill-conditioned `root` is, `|f'(root)|`. A value
+    /// near zero flags a multiple or ill-conditioned root where the reported
+    /// digits should not be trusted.
+    pub condition_number: T,
+    /// `(x_n, f(x_n))` for every iteration, present only when the `trace`
+    /// option is set.
+    pub history: Option<Vec<(T, T)>>
 }
 
+#[derive(Clone, Copy)]
 pub struct BisectionResult<T> where T: DualNumFloat {
     pub lower: T,
     pub upper: T,
@@ -69,7 +178,11 @@ pub struct RootSearchResult<T> where T: DualNumFloat {
     pub bisections: Vec<BisectionResult<T>>,
 }
 
-fn newton<'a, F, N, T>(f: F, opts: NewtonOptions<T>) -> NewtonResult<T>
+/// The plain (unsafeguarded) dual-number Newton iteration. Kept around as a
+/// regression check against [`bracketed_newton`], the safeguarded version
+/// that actually backs the [`Newton`] [`RootMethod`] used by `root_search`.
+#[cfg(test)]
+fn newton<'a, F, N, T>(f: F, opts: NewtonOptions<T>) -> Result<NewtonResult<T>, RootError<T>>
 where
     F: Fn(N) -> N + Send + Sync + 'a,
     N: Derivable<T> + Coerceable<T> + Display + Copy,
@@ -77,38 +190,625 @@ where
 {
     let mut current: T = opts.guess;
     let mut count = 0;
-    let debug = env::var("DEBUG").unwrap() == "true";
+    let mut history: Option<Vec<(T, T)>> = if opts.trace { Some(Vec::new()) } else { None };
+    let mut last_derivative: T;
     loop {
         count += 1;
         let x = N::coerce_from(current).execute_derivative();
         let z = f(x);
-        let next = x.zeroth_derivative() - z.zeroth_derivative() / z.first_derivative();
+        let derivative = z.first_derivative();
+        last_derivative = derivative;
+        if let Some(history) = history.as_mut() {
+            history.push((current, z.zeroth_derivative()));
+        }
+        if derivative.abs() < T::epsilon() {
+            if opts.debug {
+                println!("Derivative vanished near x = {}", current);
+            }
+            return Err(RootError::ZeroDerivative);
+        }
+        let next = x.zeroth_derivative() - z.zeroth_derivative() / derivative;
+        if next.is_nan() || next.is_infinite() {
+            if opts.debug {
+                println!("Iterate became NaN/Inf near x = {}", current);
+            }
+            return Err(RootError::NaNEncountered);
+        }
         let diff = next - current;
         if diff.abs() < opts.tolerance {
-            if debug {
+            if opts.debug {
                 println!("Found root at: {}", next);
             }
-            return NewtonResult{
-                root: Some(next),
-                iterations: count
-            };
+            return Ok(NewtonResult{
+                root: next,
+                iterations: count,
+                condition_number: last_derivative.abs(),
+                history
+            });
         } else {
             if count > opts.patience {
-                if debug {
+                if opts.debug {
                     println!("Failed to find root with initial guess of {}", opts.guess);
                     println!("Last iteration was: {}", current);
                     println!("Try updating the initial guess or increasing the tolerance or patience");
                 }
-                return NewtonResult{
-                    root: None,
-                    iterations: count
-                };
+                return Err(RootError::TimesUp { last: current, iterations: count });
             }
             current = next;
         }
     }
 }
 
+/// Polishes a root using `opts.order`, backed by a second derivative from
+/// [`Derivable2`]. `Halley` converges cubically near simple roots;
+/// `Schroder` trades that speed for quadratic convergence that holds near
+/// multiple roots too, where `Halley` (and plain Newton) degrade back to
+/// linear convergence.
+///
+/// The unsafeguarded counterpart to [`bracketed_newton`]'s `Halley`/`Schroder`
+/// orders; kept as a regression check against that bracketed version, which
+/// is what the [`Halley`] and [`Schroder`] [`RootMethod`]s actually use.
+#[cfg(test)]
+fn higher_order_newton<'a, F, N, T>(f: F, opts: NewtonOptions<T>) -> Result<NewtonResult<T>, RootError<T>>
+where
+    F: Fn(N) -> N + Send + Sync + 'a,
+    N: Derivable2<T> + Coerceable<T> + Display + Copy,
+    T: DualNumFloat
+{
+    let mut current: T = opts.guess;
+    let mut count = 0;
+    let two = T::from(2).unwrap();
+    let mut history: Option<Vec<(T, T)>> = if opts.trace { Some(Vec::new()) } else { None };
+    let mut last_derivative: T;
+    loop {
+        count += 1;
+        let x = N::coerce_from(current).execute_second_derivative();
+        let z = f(x);
+        let fx = z.zeroth_derivative();
+        let fprime = z.first_derivative();
+        let fprime2 = z.second_derivative();
+        last_derivative = fprime;
+        if let Some(history) = history.as_mut() {
+            history.push((current, fx));
+        }
+
+        let next = match opts.order {
+            IterationOrder::Newton => {
+                if fprime.abs() < T::epsilon() {
+                    if opts.debug {
+                        println!("Derivative vanished near x = {}", current);
+                    }
+                    return Err(RootError::ZeroDerivative);
+                }
+                current - fx / fprime
+            }
+            IterationOrder::Halley => {
+                let denominator = two * fprime * fprime - fx * fprime2;
+                if denominator.abs() < T::epsilon() {
+                    if opts.debug {
+                        println!("Halley denominator vanished near x = {}", current);
+                    }
+                    return Err(RootError::ZeroDerivative);
+                }
+                current - two * fx * fprime / denominator
+            }
+            IterationOrder::Schroder => {
+                if fprime.abs() < T::epsilon() {
+                    if opts.debug {
+                        println!("Derivative vanished near x = {}", current);
+                    }
+                    return Err(RootError::ZeroDerivative);
+                }
+                let denominator = T::one() - (fx * fprime2) / (fprime * fprime);
+                if denominator.abs() < T::epsilon() {
+                    if opts.debug {
+                        println!("Schroder denominator vanished near x = {}", current);
+                    }
+                    return Err(RootError::ZeroDerivative);
+                }
+                current - (fx / fprime) / denominator
+            }
+        };
+
+        if next.is_nan() || next.is_infinite() {
+            if opts.debug {
+                println!("Iterate became NaN/Inf near x = {}", current);
+            }
+            return Err(RootError::NaNEncountered);
+        }
+        let diff = next - current;
+        if diff.abs() < opts.tolerance {
+            if opts.debug {
+                println!("Found root at: {}", next);
+            }
+            return Ok(NewtonResult{
+                root: next,
+                iterations: count,
+                condition_number: last_derivative.abs(),
+                history
+            });
+        }
+        if count > opts.patience {
+            if opts.debug {
+                println!("Failed to find root with initial guess of {}", opts.guess);
+                println!("Last iteration was: {}", current);
+                println!("Try updating the initial guess or increasing the tolerance or patience");
+            }
+            return Err(RootError::TimesUp { last: current, iterations: count });
+        }
+        current = next;
+    }
+}
+
+/// A Newton-family iteration safeguarded by a shrinking bracket, in the
+/// spirit of Boost.Math's `newton_raphson_iterate`.
+///
+/// `opts.order` selects a Newton, Halley, or Schroder step formula, but each
+/// raw step is only accepted when it stays
+/// inside `(lo, hi)` and moves the iterate by no more than half the
+/// bracket's width; otherwise it is replaced by the bisection midpoint.
+/// Iteration stops once the bracket width or `|f|` drops below
+/// `opts.tolerance`. This guarantees convergence for
+/// any bracket in which `f` changes sign, even when the raw step would
+/// otherwise diverge on inflection points, flat regions, or (for Halley and
+/// Schroder) a poorly conditioned second derivative. `fprime2` is only
+/// required when `opts.order` is `Halley` or `Schroder`.
+///
+/// Works in terms of plain `T -> T` evaluators so it can be shared between
+/// the dual-number entry points and the [`RootMethod`] implementors.
+fn bracketed_newton<T>(
+    f: &dyn Fn(T) -> T,
+    fprime: &dyn Fn(T) -> T,
+    fprime2: Option<&dyn Fn(T) -> T>,
+    bracket: &BisectionResult<T>,
+    opts: &NewtonOptions<T>,
+) -> Result<NewtonResult<T>, RootError<T>>
+where
+    T: DualNumFloat
+{
+    let two = T::from(2).unwrap();
+    let mut lo = bracket.lower;
+    let mut hi = bracket.upper;
+    let f_lo_sign = f(lo) > T::zero();
+    let mut current = opts.guess;
+    let mut count = 0;
+    let mut history: Option<Vec<(T, T)>> = if opts.trace { Some(Vec::new()) } else { None };
+    let mut last_derivative: T;
+    loop {
+        count += 1;
+        let width = hi - lo;
+        let fx = f(current);
+        let derivative = fprime(current);
+        last_derivative = derivative;
+        if let Some(history) = history.as_mut() {
+            history.push((current, fx));
+        }
+
+        let bisection_midpoint = lo + width / two;
+        let candidate = match opts.order {
+            IterationOrder::Newton => {
+                if derivative.abs() < T::epsilon() {
+                    None
+                } else {
+                    Some(current - fx / derivative)
+                }
+            }
+            IterationOrder::Halley => {
+                let fprime2 = match fprime2 {
+                    Some(fprime2) => fprime2(current),
+                    None => return Err(RootError::MissingDerivative),
+                };
+                let denominator = two * derivative * derivative - fx * fprime2;
+                if denominator.abs() < T::epsilon() {
+                    None
+                } else {
+                    Some(current - two * fx * derivative / denominator)
+                }
+            }
+            IterationOrder::Schroder => {
+                if derivative.abs() < T::epsilon() {
+                    None
+                } else {
+                    let fprime2 = match fprime2 {
+                        Some(fprime2) => fprime2(current),
+                        None => return Err(RootError::MissingDerivative),
+                    };
+                    let denominator = T::one() - (fx * fprime2) / (derivative * derivative);
+                    if denominator.abs() < T::epsilon() {
+                        None
+                    } else {
+                        Some(current - (fx / derivative) / denominator)
+                    }
+                }
+            }
+        };
+
+        let next = match candidate {
+            None => bisection_midpoint,
+            Some(candidate) => {
+                let in_bracket = candidate > lo && candidate < hi;
+                let shrinks_enough = (candidate - current).abs() <= width / two;
+                if in_bracket && shrinks_enough {
+                    candidate
+                } else {
+                    bisection_midpoint
+                }
+            }
+        };
+
+        if next.is_nan() || next.is_infinite() {
+            if opts.debug {
+                println!("Iterate became NaN/Inf near x = {}", current);
+            }
+            return Err(RootError::NaNEncountered);
+        }
+
+        let f_next = f(next);
+        let f_next_sign = f_next > T::zero();
+        if f_next_sign == f_lo_sign {
+            lo = next;
+        } else {
+            hi = next;
+        }
+
+        if (hi - lo).abs() < opts.tolerance || f_next.abs() < opts.tolerance {
+            if opts.debug {
+                println!("Found root at: {}", next);
+            }
+            return Ok(NewtonResult{
+                root: next,
+                iterations: count,
+                condition_number: last_derivative.abs(),
+                history
+            });
+        }
+        if count > opts.patience {
+            if opts.debug {
+                println!("Failed to find root with initial guess of {}", opts.guess);
+                println!("Last iteration was: {}", current);
+                println!("Try updating the initial guess or increasing the tolerance or patience");
+            }
+            return Err(RootError::TimesUp { last: current, iterations: count });
+        }
+        current = next;
+    }
+}
+
+/// Bundles the convergence knobs shared by every [`RootMethod`], the same
+/// way [`NewtonOptions`]/[`BisectionOptions`]/[`RootSearchOptions`] bundle
+/// theirs.
+pub struct RootMethodOptions<T> where T: DualNumFloat {
+    pub patience: i32,
+    pub tolerance: T,
+    pub debug: bool,
+    pub trace: bool
+}
+
+/// A pluggable root-polishing algorithm, selected via [`RootSearchOptions::method`].
+///
+/// Mirrors Peroxide's `RootFind` abstraction: `root_search` no longer hard-codes
+/// Newton, so derivative-free methods can polish a bracket without the target
+/// function needing a usable first derivative. [`find_roots`] goes one step
+/// further and drops the `Derivable`/`Coerceable` bound entirely, for callers
+/// whose `f` is a plain `Fn(T) -> T`.
+pub trait RootMethod<T> where T: DualNumFloat {
+    /// Polish the single sign-changing `bracket` down to a root.
+    ///
+    /// `fprime` is only populated when the caller's function exposes a
+    /// derivative (i.e. when driven through the dual-number path); methods
+    /// that don't need one, such as [`Secant`] and [`Brent`], simply ignore it.
+    /// `fprime2` is populated only when the caller also exposes a second
+    /// derivative; only [`Halley`] and [`Schroder`] require it.
+    /// When `opts.trace` is set, `NewtonResult::history` is populated with
+    /// every `(x_n, f(x_n))` pair visited.
+    fn solve(
+        &self,
+        f: &dyn Fn(T) -> T,
+        fprime: Option<&dyn Fn(T) -> T>,
+        fprime2: Option<&dyn Fn(T) -> T>,
+        bracket: BisectionResult<T>,
+        opts: &RootMethodOptions<T>,
+    ) -> Result<NewtonResult<T>, RootError<T>>;
+}
+
+/// A central-difference fallback used to report a `condition_number` for
+/// methods (Secant, FalsePosition, Brent) that don't carry an analytic
+/// derivative alongside their iterate.
+fn estimate_derivative<T: DualNumFloat>(f: &dyn Fn(T) -> T, x: T) -> T {
+    let h = T::epsilon().sqrt() * (T::one() + x.abs());
+    (f(x + h) - f(x - h)) / (h + h)
+}
+
+/// Polishes with the bracketed Newton step, requiring `fprime` from the caller.
+pub struct Newton;
+
+/// Approximates the derivative from the last two iterates:
+/// `x_{n+1} = x_n - f(x_n)·(x_n - x_{n-1}) / (f(x_n) - f(x_{n-1}))`.
+pub struct Secant;
+
+/// Regula falsi: keeps a sign-changing bracket and interpolates the secant
+/// line between its endpoints, replacing whichever endpoint preserves the
+/// sign change.
+pub struct FalsePosition;
+
+/// Brent's method: inverse quadratic interpolation, falling back to the
+/// secant step and then bisection, with the standard acceptance test.
+pub struct Brent;
+
+impl<T: DualNumFloat> RootMethod<T> for Newton {
+    fn solve(
+        &self,
+        f: &dyn Fn(T) -> T,
+        fprime: Option<&dyn Fn(T) -> T>,
+        _fprime2: Option<&dyn Fn(T) -> T>,
+        bracket: BisectionResult<T>,
+        opts: &RootMethodOptions<T>,
+    ) -> Result<NewtonResult<T>, RootError<T>> {
+        let fprime = fprime.ok_or(RootError::MissingDerivative)?;
+        let guess = bracket.lower + (bracket.upper - bracket.lower) / T::from(2).unwrap();
+        bracketed_newton(f, fprime, None, &bracket, &NewtonOptions {
+            guess,
+            patience: opts.patience,
+            tolerance: opts.tolerance,
+            debug: opts.debug,
+            order: IterationOrder::Newton,
+            trace: opts.trace
+        })
+    }
+}
+
+/// Polishes with the bracketed Halley step, requiring both `fprime` and a
+/// second derivative from the caller.
+pub struct Halley;
+
+/// Polishes with the bracketed Schroder step, requiring both `fprime` and a
+/// second derivative from the caller. Slower than [`Halley`] near simple
+/// roots (quadratic rather than cubic) but remains quadratically convergent
+/// near multiple ones.
+pub struct Schroder;
+
+impl<T: DualNumFloat> RootMethod<T> for Halley {
+    fn solve(
+        &self,
+        f: &dyn Fn(T) -> T,
+        fprime: Option<&dyn Fn(T) -> T>,
+        fprime2: Option<&dyn Fn(T) -> T>,
+        bracket: BisectionResult<T>,
+        opts: &RootMethodOptions<T>,
+    ) -> Result<NewtonResult<T>, RootError<T>> {
+        let fprime = fprime.ok_or(RootError::MissingDerivative)?;
+        let guess = bracket.lower + (bracket.upper - bracket.lower) / T::from(2).unwrap();
+        bracketed_newton(f, fprime, fprime2, &bracket, &NewtonOptions {
+            guess,
+            patience: opts.patience,
+            tolerance: opts.tolerance,
+            debug: opts.debug,
+            order: IterationOrder::Halley,
+            trace: opts.trace
+        })
+    }
+}
+
+impl<T: DualNumFloat> RootMethod<T> for Schroder {
+    fn solve(
+        &self,
+        f: &dyn Fn(T) -> T,
+        fprime: Option<&dyn Fn(T) -> T>,
+        fprime2: Option<&dyn Fn(T) -> T>,
+        bracket: BisectionResult<T>,
+        opts: &RootMethodOptions<T>,
+    ) -> Result<NewtonResult<T>, RootError<T>> {
+        let fprime = fprime.ok_or(RootError::MissingDerivative)?;
+        let guess = bracket.lower + (bracket.upper - bracket.lower) / T::from(2).unwrap();
+        bracketed_newton(f, fprime, fprime2, &bracket, &NewtonOptions {
+            guess,
+            patience: opts.patience,
+            tolerance: opts.tolerance,
+            debug: opts.debug,
+            order: IterationOrder::Schroder,
+            trace: opts.trace
+        })
+    }
+}
+
+impl<T: DualNumFloat> RootMethod<T> for Secant {
+    fn solve(
+        &self,
+        f: &dyn Fn(T) -> T,
+        _fprime: Option<&dyn Fn(T) -> T>,
+        _fprime2: Option<&dyn Fn(T) -> T>,
+        bracket: BisectionResult<T>,
+        opts: &RootMethodOptions<T>,
+    ) -> Result<NewtonResult<T>, RootError<T>> {
+        let mut x_prev = bracket.lower;
+        let mut x_curr = bracket.upper;
+        let mut f_prev = f(x_prev);
+        let mut f_curr = f(x_curr);
+        let mut count = 0;
+        let mut history: Option<Vec<(T, T)>> = if opts.trace { Some(vec![(x_prev, f_prev), (x_curr, f_curr)]) } else { None };
+        loop {
+            count += 1;
+            let denom = f_curr - f_prev;
+            if denom.abs() < T::epsilon() {
+                return Err(RootError::ZeroDerivative);
+            }
+            let next = x_curr - f_curr * (x_curr - x_prev) / denom;
+            if next.is_nan() || next.is_infinite() {
+                return Err(RootError::NaNEncountered);
+            }
+            let f_next = f(next);
+            if let Some(history) = history.as_mut() {
+                history.push((next, f_next));
+            }
+            if (next - x_curr).abs() < opts.tolerance || f_next.abs() < opts.tolerance {
+                if opts.debug {
+                    println!("Found root at: {}", next);
+                }
+                return Ok(NewtonResult {
+                    root: next,
+                    iterations: count,
+                    condition_number: estimate_derivative(f, next).abs(),
+                    history
+                });
+            }
+            if count > opts.patience {
+                return Err(RootError::TimesUp { last: x_curr, iterations: count });
+            }
+            x_prev = x_curr;
+            f_prev = f_curr;
+            x_curr = next;
+            f_curr = f_next;
+        }
+    }
+}
+
+impl<T: DualNumFloat> RootMethod<T> for FalsePosition {
+    fn solve(
+        &self,
+        f: &dyn Fn(T) -> T,
+        _fprime: Option<&dyn Fn(T) -> T>,
+        _fprime2: Option<&dyn Fn(T) -> T>,
+        bracket: BisectionResult<T>,
+        opts: &RootMethodOptions<T>,
+    ) -> Result<NewtonResult<T>, RootError<T>> {
+        let mut lo = bracket.lower;
+        let mut hi = bracket.upper;
+        let mut f_lo = f(lo);
+        let mut f_hi = f(hi);
+        let mut count = 0;
+        let mut history: Option<Vec<(T, T)>> = if opts.trace { Some(vec![(lo, f_lo), (hi, f_hi)]) } else { None };
+        loop {
+            count += 1;
+            let next = (lo * f_hi - hi * f_lo) / (f_hi - f_lo);
+            let f_next = f(next);
+            if let Some(history) = history.as_mut() {
+                history.push((next, f_next));
+            }
+            if (hi - lo).abs() < opts.tolerance || f_next.abs() < opts.tolerance {
+                if opts.debug {
+                    println!("Found root at: {}", next);
+                }
+                return Ok(NewtonResult {
+                    root: next,
+                    iterations: count,
+                    condition_number: estimate_derivative(f, next).abs(),
+                    history
+                });
+            }
+            if count > opts.patience {
+                return Err(RootError::TimesUp { last: next, iterations: count });
+            }
+            let next_positive = f_next > T::zero();
+            if next_positive == (f_lo > T::zero()) {
+                lo = next;
+                f_lo = f_next;
+            } else {
+                hi = next;
+                f_hi = f_next;
+            }
+        }
+    }
+}
+
+impl<T: DualNumFloat> RootMethod<T> for Brent {
+    fn solve(
+        &self,
+        f: &dyn Fn(T) -> T,
+        _fprime: Option<&dyn Fn(T) -> T>,
+        _fprime2: Option<&dyn Fn(T) -> T>,
+        bracket: BisectionResult<T>,
+        opts: &RootMethodOptions<T>,
+    ) -> Result<NewtonResult<T>, RootError<T>> {
+        let two = T::from(2).unwrap();
+        let three = T::from(3).unwrap();
+        let four = T::from(4).unwrap();
+
+        let mut a = bracket.lower;
+        let mut b = bracket.upper;
+        let mut fa = f(a);
+        let mut fb = f(b);
+        if fa.abs() < fb.abs() {
+            std::mem::swap(&mut a, &mut b);
+            std::mem::swap(&mut fa, &mut fb);
+        }
+        let mut c = a;
+        let mut fc = fa;
+        let mut d = a;
+        let mut mflag = true;
+        let mut count = 0;
+        let mut history: Option<Vec<(T, T)>> = if opts.trace { Some(vec![(b, fb)]) } else { None };
+
+        loop {
+            if fb.abs() < opts.tolerance || (b - a).abs() < opts.tolerance {
+                if opts.debug {
+                    println!("Found root at: {}", b);
+                }
+                return Ok(NewtonResult {
+                    root: b,
+                    iterations: count,
+                    condition_number: estimate_derivative(f, b).abs(),
+                    history
+                });
+            }
+            count += 1;
+            if count > opts.patience {
+                return Err(RootError::TimesUp { last: b, iterations: count });
+            }
+
+            let s = if fa != fc && fb != fc {
+                a * fb * fc / ((fa - fb) * (fa - fc))
+                    + b * fa * fc / ((fb - fa) * (fb - fc))
+                    + c * fa * fb / ((fc - fa) * (fc - fb))
+            } else {
+                b - fb * (b - a) / (fb - fa)
+            };
+
+            let out_of_range = (s - b) * (s - (three * a + b) / four) > T::zero();
+            let not_shrinking = if mflag {
+                (s - b).abs() >= (b - c).abs() / two
+            } else {
+                (s - b).abs() >= (c - d).abs() / two
+            };
+            let step_too_small = if mflag {
+                (b - c).abs() < opts.tolerance
+            } else {
+                (c - d).abs() < opts.tolerance
+            };
+
+            let s = if out_of_range || not_shrinking || step_too_small {
+                mflag = true;
+                a + (b - a) / two
+            } else {
+                mflag = false;
+                s
+            };
+
+            if s.is_nan() || s.is_infinite() {
+                return Err(RootError::NaNEncountered);
+            }
+
+            let fs = f(s);
+            if let Some(history) = history.as_mut() {
+                history.push((s, fs));
+            }
+            d = c;
+            c = b;
+            fc = fb;
+            if (fa > T::zero()) != (fs > T::zero()) {
+                b = s;
+                fb = fs;
+            } else {
+                a = s;
+                fa = fs;
+            }
+            if fa.abs() < fb.abs() {
+                std::mem::swap(&mut a, &mut b);
+                std::mem::swap(&mut fa, &mut fb);
+            }
+        }
+    }
+}
+
 fn find_bisections<F, N, T>(f: F, opts: BisectionOptions<T>) -> Vec<BisectionResult<T>>
 where
     F: Fn(N) -> N + Sync + Send + Copy,
@@ -133,52 +833,266 @@ where
     values
 }
 
-pub fn root_search<F, N, T>(f: F, opts: RootSearchOptions<T>) -> RootSearchResult<T>
+pub fn root_search<F, N, T>(f: F, opts: RootSearchOptions<T>) -> Result<RootSearchResult<T>, RootError<T>>
 where
     F: Fn(N) -> N + Sync + Send + Copy,
-    N: Derivable<T> + Coerceable<T> + Display + Copy + Sub + Div,
+    N: Derivable2<T> + Coerceable<T> + Display + Copy + Sub + Div,
     T: DualNumFloat
 {
-    if opts.lower > opts.upper {
-        panic!("Lower bound must be greater than upper bound")
-    }
-    if opts.lower == opts.upper {
-        panic!("Bounds cannot be the same")
+    if opts.lower >= opts.upper {
+        return Err(RootError::InvalidBounds);
     }
     let bisections = find_bisections(f, BisectionOptions{
         lower: opts.lower,
         upper: opts.upper,
         resolution: opts.resolution
     });
+    let value_at = |x: T| f(N::coerce_from(x)).zeroth_derivative();
+    let derivative_at = |x: T| f(N::coerce_from(x).execute_derivative()).first_derivative();
+    let second_derivative_at = |x: T| f(N::coerce_from(x).execute_second_derivative()).second_derivative();
+    let method_opts = RootMethodOptions {
+        patience: opts.patience,
+        tolerance: opts.tolerance,
+        debug: opts.debug,
+        trace: opts.trace
+    };
     let mut roots: Vec<T> = Vec::new();
-    for bisection in &bisections {
-        let res = T::from(100).unwrap();
-        let step = (bisection.upper - bisection.lower) / res;
-        for i in 0..res.to_i32().unwrap() {
-            let guess = bisection.lower + (T::from(i).unwrap() * step);
-            let res = newton(f, NewtonOptions{
-                guess: guess,
-                patience: opts.patience,
-                tolerance: opts.tolerance
-            });
-            if res.root.is_none() {
-                break;
+    for bisection in bisections.iter().cloned() {
+        let res = opts.method.solve(&value_at, Some(&derivative_at), Some(&second_derivative_at), bisection, &method_opts);
+        if let Ok(res) = res {
+            roots.push(res.root);
+        }
+    }
+    Ok(RootSearchResult{roots, bisections})
+}
+
+/// Scans `[lower, upper]` for sign changes the same way [`find_bisections`] does,
+/// but evaluates `f` directly on plain `T` rather than coercing into a dual
+/// number — the bisection step itself never needs a derivative.
+fn find_bisections_plain<F, T>(f: F, opts: BisectionOptions<T>) -> Vec<BisectionResult<T>>
+where
+    F: Fn(T) -> T + Sync + Send + Copy,
+    T: DualNumFloat
+{
+    let step = (opts.upper - opts.lower) / T::from(opts.resolution).unwrap() + T::epsilon();
+    // Add off-set to step to deal with roots at middle of lower and upper range
+    let mut values: Vec<BisectionResult<T>> = Vec::new();
+
+    for i in 0..opts.resolution {
+        let a = opts.lower + step * T::from(i).unwrap();
+        let b = opts.lower + step * T::from(i+1).unwrap();
+        let fa = f(a);
+        let fb = f(b);
+        let pos2neg = fa > T::zero() && fb < T::zero();
+        let neg2pos = fa < T::zero() && fb > T::zero();
+        if pos2neg || neg2pos {
+            values.push(BisectionResult{lower: a, upper: b});
+        }
+    };
+    values
+}
+
+/// Derivative-free counterpart to [`root_search`]: takes a plain `Fn(T) -> T`
+/// closure instead of one returning a [`Derivable`] dual number, so methods
+/// that never touch `f'` (e.g. [`Secant`], [`FalsePosition`], [`Brent`]) can be
+/// driven without the caller having to supply a differentiable `f` at all.
+pub fn find_roots<F, T>(f: F, opts: RootSearchOptions<T>) -> Result<RootSearchResult<T>, RootError<T>>
+where
+    F: Fn(T) -> T + Sync + Send + Copy,
+    T: DualNumFloat
+{
+    if opts.lower >= opts.upper {
+        return Err(RootError::InvalidBounds);
+    }
+    let bisections = find_bisections_plain(f, BisectionOptions{
+        lower: opts.lower,
+        upper: opts.upper,
+        resolution: opts.resolution
+    });
+    let method_opts = RootMethodOptions {
+        patience: opts.patience,
+        tolerance: opts.tolerance,
+        debug: opts.debug,
+        trace: opts.trace
+    };
+    let mut roots: Vec<T> = Vec::new();
+    for bisection in bisections.iter().cloned() {
+        let res = opts.method.solve(&f, None, None, bisection, &method_opts);
+        if let Ok(res) = res {
+            roots.push(res.root);
+        }
+    }
+    Ok(RootSearchResult{roots, bisections})
+}
+
+/// A complex dual number: a complex value paired with its complex derivative.
+///
+/// Generalizes [`Derivable`]/[`Coerceable`] to the complex plane so
+/// [`complex_newton`] can locate complex conjugate roots (e.g. of polynomials
+/// with no real zeros) the same way `newton` does for real ones.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComplexDual<T> where T: DualNumFloat {
+    pub value: Complex<T>,
+    pub derivative: Complex<T>,
+}
+
+impl<T: DualNumFloat> ComplexDual<T> {
+    /// A constant with zero derivative, for literals appearing in `f`.
+    pub fn constant(value: Complex<T>) -> Self {
+        ComplexDual { value, derivative: Complex::new(T::zero(), T::zero()) }
+    }
+
+    /// The independent variable: derivative `1 + 0i`.
+    pub fn variable(value: Complex<T>) -> Self {
+        ComplexDual { value, derivative: Complex::new(T::one(), T::zero()) }
+    }
+}
+
+impl<T: DualNumFloat> Add for ComplexDual<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        ComplexDual { value: self.value + rhs.value, derivative: self.derivative + rhs.derivative }
+    }
+}
+
+impl<T: DualNumFloat> Sub for ComplexDual<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        ComplexDual { value: self.value - rhs.value, derivative: self.derivative - rhs.derivative }
+    }
+}
+
+impl<T: DualNumFloat> Mul for ComplexDual<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        ComplexDual {
+            value: self.value * rhs.value,
+            derivative: self.derivative * rhs.value + self.value * rhs.derivative,
+        }
+    }
+}
+
+impl<T: DualNumFloat> Div for ComplexDual<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        ComplexDual {
+            value: self.value / rhs.value,
+            derivative: (self.derivative * rhs.value - self.value * rhs.derivative) / (rhs.value * rhs.value),
+        }
+    }
+}
+
+pub struct ComplexNewtonOptions<T> where T: DualNumFloat {
+    pub guess: Complex<T>,
+    pub patience: i32,
+    pub tolerance: T,
+    pub debug: bool
+}
+
+pub struct ComplexNewtonResult<T> where T: DualNumFloat {
+    pub root: Complex<T>,
+    pub iterations: i32
+}
+
+/// Mirrors [`RootError`] for the complex-valued entry points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComplexRootError<T> where T: DualNumFloat {
+    TimesUp { last: Complex<T>, iterations: i32 },
+    NaNEncountered,
+    ZeroDerivative,
+}
+
+/// Complex Newton iteration, analogous to Boost.Math's complex
+/// `newton_raphson_iterate`: `z_{n+1} = z_n - f(z_n)/f'(z_n)`, terminating
+/// once `|Δz|` drops below `tolerance`.
+pub fn complex_newton<F, T>(f: F, opts: ComplexNewtonOptions<T>) -> Result<ComplexNewtonResult<T>, ComplexRootError<T>>
+where
+    F: Fn(ComplexDual<T>) -> ComplexDual<T>,
+    T: DualNumFloat
+{
+    let mut current = opts.guess;
+    let mut count = 0;
+    loop {
+        count += 1;
+        let z = f(ComplexDual::variable(current));
+        if z.derivative.norm() < T::epsilon() {
+            if opts.debug {
+                println!("Derivative vanished near z = {}", current);
+            }
+            return Err(ComplexRootError::ZeroDerivative);
+        }
+        let next = current - z.value / z.derivative;
+        if !next.re.is_finite() || !next.im.is_finite() {
+            if opts.debug {
+                println!("Iterate became NaN/Inf near z = {}", current);
+            }
+            return Err(ComplexRootError::NaNEncountered);
+        }
+        let diff = (next - current).norm();
+        if diff < opts.tolerance {
+            if opts.debug {
+                println!("Found root at: {}", next);
             }
-            let root = res.root.unwrap();
-            if bisection.lower < root && root < bisection.upper {
-                roots.push(root);
-                break;
+            return Ok(ComplexNewtonResult { root: next, iterations: count });
+        }
+        if count > opts.patience {
+            if opts.debug {
+                println!("Failed to find root with initial guess of {}", opts.guess);
+                println!("Last iteration was: {}", current);
             }
+            return Err(ComplexRootError::TimesUp { last: current, iterations: count });
         }
+        current = next;
+    }
+}
+
+pub struct ComplexRootSearchOptions<T> where T: DualNumFloat {
+    pub guesses: Vec<Complex<T>>,
+    pub patience: i32,
+    pub tolerance: T,
+    pub debug: bool,
+    pub dedupe_tolerance: T
+}
 
+/// Runs [`complex_newton`] from each of `opts.guesses`, deduplicating roots
+/// that land within `dedupe_tolerance` of one another and deflating each
+/// newly found root out of `f` so that later guesses converge onto a
+/// different root instead of re-finding the same one.
+pub fn find_complex_roots<F, T>(f: F, opts: ComplexRootSearchOptions<T>) -> Vec<Complex<T>>
+where
+    F: Fn(ComplexDual<T>) -> ComplexDual<T> + Copy,
+    T: DualNumFloat
+{
+    let mut roots: Vec<Complex<T>> = Vec::new();
+    for guess in opts.guesses.iter().cloned() {
+        let found = roots.clone();
+        let deflated = move |x: ComplexDual<T>| {
+            let mut fx = f(x);
+            for root in &found {
+                fx = fx / (x - ComplexDual::constant(*root));
+            }
+            fx
+        };
+        let res = complex_newton(deflated, ComplexNewtonOptions {
+            guess,
+            patience: opts.patience,
+            tolerance: opts.tolerance,
+            debug: opts.debug,
+        });
+        if let Ok(res) = res {
+            let is_new = roots.iter().all(|r| (*r - res.root).norm() > opts.dedupe_tolerance);
+            if is_new {
+                roots.push(res.root);
+            }
+        }
     }
-    RootSearchResult{roots, bisections}
+    roots
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use num_dual::{Dual32, DualNum};
+    use num_dual::{Dual32, Dual2_32, DualNum};
 
     #[test]
     fn find_sine_root_newton() {
@@ -188,9 +1102,12 @@ mod tests {
         let res = newton::<_,Dual32,f32>(&sine, NewtonOptions{
             guess: 2.0,
             patience: 1000,
-            tolerance: 0.0001
-        });
-        assert_eq!(std::f32::consts::PI, res.root.unwrap())
+            tolerance: 0.0001,
+            debug: false,
+            order: IterationOrder::Newton,
+            trace: false
+        }).unwrap();
+        assert_eq!(std::f32::consts::PI, res.root)
     }
 
     #[test]
@@ -201,9 +1118,44 @@ mod tests {
         let res = newton::<_,Dual32,f32>(&cosine, NewtonOptions{
             guess: 2.0,
             patience: 1000,
-            tolerance: 0.0001
-        });
-        assert_eq!(std::f32::consts::PI / 2.0, res.root.unwrap())
+            tolerance: 0.0001,
+            debug: false,
+            order: IterationOrder::Newton,
+            trace: false
+        }).unwrap();
+        assert_eq!(std::f32::consts::PI / 2.0, res.root)
+    }
+
+    #[test]
+    fn find_sine_root_halley() {
+        fn sine<D: DualNum<f32>>(x: D) -> D {
+            x.sin()
+        }
+        let res = higher_order_newton::<_,Dual2_32,f32>(&sine, NewtonOptions{
+            guess: 2.0,
+            patience: 1000,
+            tolerance: 0.0001,
+            debug: false,
+            order: IterationOrder::Halley,
+            trace: false
+        }).unwrap();
+        assert_eq!(std::f32::consts::PI, res.root)
+    }
+
+    #[test]
+    fn find_sine_root_schroder() {
+        fn sine<D: DualNum<f32>>(x: D) -> D {
+            x.sin()
+        }
+        let res = higher_order_newton::<_,Dual2_32,f32>(&sine, NewtonOptions{
+            guess: 2.0,
+            patience: 1000,
+            tolerance: 0.0001,
+            debug: false,
+            order: IterationOrder::Schroder,
+            trace: false
+        }).unwrap();
+        assert_eq!(std::f32::consts::PI, res.root)
     }
 
     #[test]
@@ -243,20 +1195,23 @@ mod tests {
         fn sine<D: DualNum<f32>>(x: D) -> D {
             x.sin()
         }
-        let res = root_search::<_,Dual32,f32>(&sine, RootSearchOptions{
+        let res = root_search::<_,Dual2_32,f32>(&sine, RootSearchOptions{
             lower: -5.0,
             upper: 5.0,
             patience: 2000,
             tolerance: 0.0001,
-            resolution: 1000
-        });
+            resolution: 1000,
+            debug: false,
+            method: Box::new(Newton),
+            trace: false
+        }).unwrap();
         for root in &res.roots {
             println!("root: {}", root);
         }
         assert_eq!(res.roots.len(), 3);
-        assert!(res.roots.contains(&std::f32::consts::PI));
-        assert!(res.roots.contains(&(-std::f32::consts::PI)));
-        assert!(res.roots.contains(&0.0));
+        assert!(res.roots.iter().any(|r| (r - std::f32::consts::PI).abs() < 0.001));
+        assert!(res.roots.iter().any(|r| (r + std::f32::consts::PI).abs() < 0.001));
+        assert!(res.roots.iter().any(|r| r.abs() < 0.001));
     }
 
     #[test]
@@ -264,22 +1219,168 @@ mod tests {
         fn cosine<D: DualNum<f32>>(x: D) -> D {
             x.cos()
         }
-        let res = root_search::<_,Dual32,f32>(&cosine, RootSearchOptions{
+        let res = root_search::<_,Dual2_32,f32>(&cosine, RootSearchOptions{
             lower: -5.0,
             upper: 5.0,
             patience: 2000,
             tolerance: 0.0001,
-            resolution: 1000
-        });
+            resolution: 1000,
+            debug: false,
+            method: Box::new(Newton),
+            trace: false
+        }).unwrap();
         for root in &res.roots {
             println!("root: {}", root);
         }
         assert_eq!(res.roots.len(), 4);
-        assert!(res.roots.contains(&std::f32::consts::FRAC_PI_2));
-        assert!(res.roots.contains(&(-std::f32::consts::FRAC_PI_2)));
-        assert!(res.roots.contains(&(std::f32::consts::FRAC_PI_2 * 3.0)));
-        assert!(res.roots.contains(&(-std::f32::consts::FRAC_PI_2 * 3.0)));
+        assert!(res.roots.iter().any(|r| (r - std::f32::consts::FRAC_PI_2).abs() < 0.001));
+        assert!(res.roots.iter().any(|r| (r + std::f32::consts::FRAC_PI_2).abs() < 0.001));
+        assert!(res.roots.iter().any(|r| (r - std::f32::consts::FRAC_PI_2 * 3.0).abs() < 0.001));
+        assert!(res.roots.iter().any(|r| (r + std::f32::consts::FRAC_PI_2 * 3.0).abs() < 0.001));
+    }
+
+    #[test]
+    fn find_sine_roots_with_schroder() {
+        fn sine<D: DualNum<f32>>(x: D) -> D {
+            x.sin()
+        }
+        let res = root_search::<_,Dual2_32,f32>(&sine, RootSearchOptions{
+            lower: -5.0,
+            upper: 5.0,
+            patience: 2000,
+            tolerance: 0.0001,
+            resolution: 1000,
+            debug: false,
+            method: Box::new(Schroder),
+            trace: false
+        }).unwrap();
+        assert_eq!(res.roots.len(), 3);
+        assert!(res.roots.iter().any(|r| (r - std::f32::consts::PI).abs() < 0.001));
+    }
+
+    #[test]
+    fn find_sine_roots_with_secant() {
+        fn sine<D: DualNum<f32>>(x: D) -> D {
+            x.sin()
+        }
+        let res = root_search::<_,Dual2_32,f32>(&sine, RootSearchOptions{
+            lower: -5.0,
+            upper: 5.0,
+            patience: 2000,
+            tolerance: 0.0001,
+            resolution: 1000,
+            debug: false,
+            method: Box::new(Secant),
+            trace: false
+        }).unwrap();
+        assert_eq!(res.roots.len(), 3);
+        assert!(res.roots.iter().any(|r| (r - std::f32::consts::PI).abs() < 0.001));
+    }
+
+    #[test]
+    fn find_sine_roots_with_false_position() {
+        fn sine<D: DualNum<f32>>(x: D) -> D {
+            x.sin()
+        }
+        let res = root_search::<_,Dual2_32,f32>(&sine, RootSearchOptions{
+            lower: -5.0,
+            upper: 5.0,
+            patience: 2000,
+            tolerance: 0.0001,
+            resolution: 1000,
+            debug: false,
+            method: Box::new(FalsePosition),
+            trace: false
+        }).unwrap();
+        assert_eq!(res.roots.len(), 3);
+        assert!(res.roots.iter().any(|r| (r - std::f32::consts::PI).abs() < 0.001));
+    }
+
+    #[test]
+    fn find_sine_roots_with_brent() {
+        fn sine<D: DualNum<f32>>(x: D) -> D {
+            x.sin()
+        }
+        let res = root_search::<_,Dual2_32,f32>(&sine, RootSearchOptions{
+            lower: -5.0,
+            upper: 5.0,
+            patience: 2000,
+            tolerance: 0.0001,
+            resolution: 1000,
+            debug: false,
+            method: Box::new(Brent),
+            trace: false
+        }).unwrap();
+        assert_eq!(res.roots.len(), 3);
+        assert!(res.roots.iter().any(|r| (r - std::f32::consts::PI).abs() < 0.001));
+    }
+
+    #[test]
+    fn find_sine_roots_with_plain_closure() {
+        let sine = |x: f32| x.sin();
+        let res = find_roots(sine, RootSearchOptions{
+            lower: -5.0,
+            upper: 5.0,
+            patience: 2000,
+            tolerance: 0.0001,
+            resolution: 1000,
+            debug: false,
+            method: Box::new(Brent),
+            trace: false
+        }).unwrap();
+        assert_eq!(res.roots.len(), 3);
+        assert!(res.roots.iter().any(|r| (r - std::f32::consts::PI).abs() < 0.001));
     }
 
+    #[test]
+    fn newton_reports_condition_number_and_history() {
+        fn sine<D: DualNum<f32>>(x: D) -> D {
+            x.sin()
+        }
+        let res = newton::<_,Dual32,f32>(&sine, NewtonOptions{
+            guess: 2.0,
+            patience: 1000,
+            tolerance: 0.0001,
+            debug: false,
+            order: IterationOrder::Newton,
+            trace: true
+        }).unwrap();
+        // f'(pi) = cos(pi) = -1, so the root is well-conditioned
+        assert!((res.condition_number - 1.0).abs() < 0.001);
+        let history = res.history.unwrap();
+        assert_eq!(history.len() as i32, res.iterations);
+        assert_eq!(history[0], (2.0, sine(2.0)));
+    }
 
+    #[test]
+    fn find_complex_root_of_z_squared_plus_one() {
+        // z^2 + 1 has no real roots, only z = +-i
+        fn polynomial(z: ComplexDual<f32>) -> ComplexDual<f32> {
+            z * z + ComplexDual::constant(Complex::new(1.0, 0.0))
+        }
+        let res = complex_newton(polynomial, ComplexNewtonOptions{
+            guess: Complex::new(0.5, 0.5),
+            patience: 1000,
+            tolerance: 0.0001,
+            debug: false
+        }).unwrap();
+        assert!((res.root - Complex::new(0.0, 1.0)).norm() < 0.001);
+    }
+
+    #[test]
+    fn find_both_complex_roots_with_deflation() {
+        fn polynomial(z: ComplexDual<f32>) -> ComplexDual<f32> {
+            z * z + ComplexDual::constant(Complex::new(1.0, 0.0))
+        }
+        let roots = find_complex_roots(polynomial, ComplexRootSearchOptions{
+            guesses: vec![Complex::new(0.5, 0.5), Complex::new(0.5, -0.5)],
+            patience: 1000,
+            tolerance: 0.0001,
+            debug: false,
+            dedupe_tolerance: 0.001
+        });
+        assert_eq!(roots.len(), 2);
+        assert!(roots.iter().any(|r| (*r - Complex::new(0.0, 1.0)).norm() < 0.001));
+        assert!(roots.iter().any(|r| (*r - Complex::new(0.0, -1.0)).norm() < 0.001));
+    }
 }
\ No newline at end of file